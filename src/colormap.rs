@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+/// The selectable palettes for mapping a normalized escape value `n` in `[0, 1]` to a
+/// pixel color. Cycled at runtime with `C`.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Colormap {
+    #[default]
+    Classic,
+    Fire,
+    Ocean,
+    Grayscale,
+    HsvCycle,
+}
+
+impl Colormap {
+    /// Cycles to the next palette in the fixed rotation.
+    pub fn next(self) -> Self {
+        match self {
+            Colormap::Classic => Colormap::Fire,
+            Colormap::Fire => Colormap::Ocean,
+            Colormap::Ocean => Colormap::Grayscale,
+            Colormap::Grayscale => Colormap::HsvCycle,
+            Colormap::HsvCycle => Colormap::Classic,
+        }
+    }
+
+    /// A human-readable label for the HUD.
+    pub fn label(self) -> &'static str {
+        match self {
+            Colormap::Classic => "Classic",
+            Colormap::Fire => "Fire",
+            Colormap::Ocean => "Ocean",
+            Colormap::Grayscale => "Grayscale",
+            Colormap::HsvCycle => "HSV-cycle",
+        }
+    }
+
+    /// The shader-side index; must match the branches in `shaders/mandelbrot.wgsl`.
+    pub fn shader_index(self) -> i32 {
+        match self {
+            Colormap::Classic => 0,
+            Colormap::Fire => 1,
+            Colormap::Ocean => 2,
+            Colormap::Grayscale => 3,
+            Colormap::HsvCycle => 4,
+        }
+    }
+
+    /// Maps a normalized escape value to an RGBA pixel. `hue_offset_degrees` only
+    /// affects `HsvCycle`.
+    pub fn color(self, n: f32, hue_offset_degrees: f32) -> [u8; 4] {
+        let rgb = match self {
+            Colormap::Classic => classic(n),
+            Colormap::Fire => gradient(n, &FIRE_STOPS),
+            Colormap::Ocean => gradient(n, &OCEAN_STOPS),
+            Colormap::Grayscale => Vec3::splat(n),
+            Colormap::HsvCycle => hsv_cycle(n, hue_offset_degrees),
+        };
+        [
+            (rgb.x.clamp(0.0, 1.0) * 255.0) as u8,
+            (rgb.y.clamp(0.0, 1.0) * 255.0) as u8,
+            (rgb.z.clamp(0.0, 1.0) * 255.0) as u8,
+            255,
+        ]
+    }
+}
+
+/// The user-adjustable hue rotation (in degrees), applied on top of `Colormap::HsvCycle`.
+/// Adjusted at runtime with `[` / `]`.
+#[derive(Resource, Clone, Copy)]
+pub struct HueOffset(pub f32);
+
+impl Default for HueOffset {
+    fn default() -> Self {
+        Self(0.0)
+    }
+}
+
+// A handful of gradient control points, evenly spaced across [0, 1] and linearly
+// interpolated between the two bracketing stops.
+const FIRE_STOPS: [Vec3; 4] = [
+    Vec3::new(0.0, 0.0, 0.0),
+    Vec3::new(0.6, 0.0, 0.0),
+    Vec3::new(1.0, 0.6, 0.0),
+    Vec3::new(1.0, 1.0, 0.7),
+];
+const OCEAN_STOPS: [Vec3; 4] = [
+    Vec3::new(0.0, 0.0, 0.05),
+    Vec3::new(0.0, 0.1, 0.35),
+    Vec3::new(0.0, 0.5, 0.7),
+    Vec3::new(0.7, 1.0, 1.0),
+];
+
+/// The original hard-wired polynomial RGB formula, kept as the default palette.
+fn classic(n: f32) -> Vec3 {
+    Vec3::new(
+        9.0 * (1.0 - n) * n * n * n,
+        15.0 * (1.0 - n) * (1.0 - n) * n * n,
+        8.5 * (1.0 - n) * (1.0 - n) * (1.0 - n) * n,
+    )
+}
+
+fn gradient(n: f32, stops: &[Vec3; 4]) -> Vec3 {
+    let scaled = n.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+    let idx = scaled.floor() as usize;
+    let t = scaled.fract();
+    let a = stops[idx];
+    let b = stops[(idx + 1).min(stops.len() - 1)];
+    a.lerp(b, t)
+}
+
+fn hsv_cycle(n: f32, hue_offset_degrees: f32) -> Vec3 {
+    let hue = (n * 360.0 + hue_offset_degrees).rem_euclid(360.0);
+    hsv_to_rgb(hue, 1.0, 1.0)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Vec3 {
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+    let rgb = match (h / 60.0) as u32 {
+        0 => Vec3::new(c, x, 0.0),
+        1 => Vec3::new(x, c, 0.0),
+        2 => Vec3::new(0.0, c, x),
+        3 => Vec3::new(0.0, x, c),
+        4 => Vec3::new(x, 0.0, c),
+        _ => Vec3::new(c, 0.0, x),
+    };
+    rgb + Vec3::splat(m)
+}
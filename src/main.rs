@@ -2,18 +2,48 @@ use bevy::{
     input::mouse::MouseWheel,
     math::DVec2,
     prelude::*,
-    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    render::render_resource::{
+        AsBindGroup, Extent3d, ShaderRef, ShaderType, TextureDimension, TextureFormat,
+    },
+    sprite::{Material2d, Material2dPlugin, MaterialMesh2dBundle},
     window::WindowResized,
 };
+use image::RgbaImage;
+use rand::Rng;
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-// The maximum number of iterations to check for escape. Higher values are more detailed but slower.
-const MAX_ITERATIONS: u32 = 512;
+mod colormap;
 
-// A resource to manage the current view (position and scale) of the complex plane.
+use colormap::{Colormap, HueOffset};
+
+// The default number of iterations to check for escape. Higher values are more detailed but slower.
+const DEFAULT_ITERATIONS: u32 = 512;
+// The range `ComplexPlaneView::iterations` is clamped to when adjusted at runtime.
+const MIN_ITERATIONS: u32 = 16;
+const MAX_ITERATIONS: u32 = 65536;
+// How close together (in seconds) two right-clicks must land to count as a double-click.
+const DOUBLE_CLICK_WINDOW_SECS: f32 = 0.3;
+// How many view-widths `center` may drift from the GPU reference point before
+// `update_mandelbrot_material` re-centers it. See that function for why this exists.
+const GPU_REFERENCE_RECENTER_FACTOR: f64 = 2.0;
+
+// Which family of fractal the iteration loop produces.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FractalMode {
+    Mandelbrot,
+    Julia,
+}
+
+// A resource to manage the current view (position, scale, and detail) of the complex plane.
 #[derive(Resource)]
 struct ComplexPlaneView {
     center: DVec2,
     scale: f64, // Represents the horizontal width of the view in the complex plane
+    iterations: u32,
+    seed: DVec2, // The fixed c used in Julia mode; set live via right-click-drag
+    mode: FractalMode,
 }
 
 impl Default for ComplexPlaneView {
@@ -22,31 +52,168 @@ impl Default for ComplexPlaneView {
             // Start centered on a more interesting area
             center: DVec2::new(-0.75, 0.0),
             scale: 3.5,
+            iterations: DEFAULT_ITERATIONS,
+            seed: DVec2::ZERO,
+            mode: FractalMode::Mandelbrot,
         }
     }
 }
 
-// A marker component for the sprite that will display the Mandelbrot set image.
+// Which rasterizer currently drives the on-screen image: the GPU shader, the CPU
+// fallback, or the Buddhabrot sampler. Cycled at runtime with `Tab`.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+enum RenderMode {
+    #[default]
+    Gpu,
+    Cpu,
+    Buddhabrot,
+}
+
+// The sample count used by the Buddhabrot renderer. Adjusted at runtime with `Up`/`Down`.
+#[derive(Resource, Clone, Copy)]
+struct BuddhabrotSamples(u32);
+
+impl Default for BuddhabrotSamples {
+    fn default() -> Self {
+        Self(2_000_000)
+    }
+}
+
+const MIN_BUDDHABROT_SAMPLES: u32 = 10_000;
+const MAX_BUDDHABROT_SAMPLES: u32 = 50_000_000;
+
+// The range each dimension of `ExportResolution` is clamped to when adjusted at runtime.
+const MIN_EXPORT_DIMENSION: u32 = 480;
+const MAX_EXPORT_DIMENSION: u32 = 15360;
+
+// A marker component for the quad mesh that displays the Mandelbrot set.
+#[derive(Component)]
+struct MandelbrotMesh;
+
+// A resource to hold the handle to the material driving the GPU shader.
+#[derive(Resource)]
+struct MandelbrotMaterialHandle(Handle<MandelbrotMaterial>);
+
+// A marker component for the sprite used by the CPU fallback rasterizer.
 #[derive(Component)]
 struct MandelbrotSprite;
 
-// A resource to hold the handle to our dynamically generated image.
+// A resource to hold the handle to the CPU fallback's dynamically generated image.
 #[derive(Resource)]
 struct MandelbrotImage(Handle<Image>);
 
+// A marker component for the HUD text showing the current center, scale, and iteration limit.
+#[derive(Component)]
+struct HudText;
+
+// The uniform data passed to `shaders/mandelbrot.wgsl`, packed into a single binding.
+//
+// `center`/`seed` are not uploaded directly: each gets its own f64 reference point that
+// trails it (re-centered on large jumps, see `update_mandelbrot_material`), plus a small
+// f64 delta from that reference, both narrowed to f32. The two have independent
+// references because in Julia mode the seed is fixed once via right-click-drag while
+// `center` keeps moving as the user explores around it. The shader reconstructs
+// `c`/`z0` as `reference + delta` in f32, so the GPU image carries f32 precision only
+// (~7 significant digits) and loses fractional detail once `scale` drops much below ~1e-5.
+#[derive(Clone, Copy, ShaderType)]
+struct MandelbrotParams {
+    center_reference: Vec2,
+    center_delta: Vec2,
+    scale: f32,
+    aspect: f32,
+    iters: i32,
+    seed_reference: Vec2,
+    seed_delta: Vec2,
+    mode: i32,     // 0 = Mandelbrot, 1 = Julia; mirrors `FractalMode`
+    colormap: i32, // mirrors `Colormap::shader_index`
+    hue_offset: f32,
+}
+
+// The GPU material that evaluates the Mandelbrot set per-fragment.
+#[derive(Asset, TypePath, AsBindGroup, Clone)]
+struct MandelbrotMaterial {
+    #[uniform(0)]
+    params: MandelbrotParams,
+}
+
+impl Material2d for MandelbrotMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/mandelbrot.wgsl".into()
+    }
+}
+
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
+        .add_plugins(Material2dPlugin::<MandelbrotMaterial>::default())
         .init_resource::<ComplexPlaneView>()
-        .add_systems(Startup, (setup_camera, setup_mandelbrot_image))
+        .init_resource::<RenderMode>()
+        .init_resource::<Colormap>()
+        .init_resource::<HueOffset>()
+        .init_resource::<ExportResolution>()
+        .init_resource::<BuddhabrotSamples>()
+        .add_systems(
+            Startup,
+            (
+                setup_camera,
+                setup_mandelbrot_mesh,
+                setup_mandelbrot_image,
+                setup_hud,
+            ),
+        )
         .add_systems(
             Update,
             (
                 handle_panning,
                 handle_zoom,
-                // This system now only runs if the view has changed or the window was resized.
-                draw_mandelbrot_set.run_if(
-                    resource_changed::<ComplexPlaneView>.or_else(on_event::<WindowResized>()),
+                handle_seed_drag,
+                adjust_iterations,
+                toggle_render_mode,
+                cycle_colormap,
+                adjust_hue_offset,
+                adjust_buddhabrot_samples,
+                adjust_export_resolution,
+                export_view_to_png,
+                update_render_mode_visibility.run_if(resource_changed::<RenderMode>),
+                // The material uniform only needs to be refreshed when the view, colormap,
+                // or hue offset changes, or the window is resized; the actual per-fragment
+                // work runs on the GPU.
+                update_mandelbrot_material.run_if(
+                    resource_changed::<ComplexPlaneView>
+                        .or_else(on_event::<WindowResized>())
+                        .or_else(resource_changed::<Colormap>)
+                        .or_else(resource_changed::<HueOffset>),
+                ),
+                // The CPU path is a fallback/offline rasterizer: it only needs to run
+                // while it's the active render mode.
+                draw_mandelbrot_set
+                    .run_if(resource_equals(RenderMode::Cpu))
+                    .run_if(
+                        resource_changed::<ComplexPlaneView>
+                            .or_else(on_event::<WindowResized>())
+                            .or_else(resource_changed::<RenderMode>)
+                            .or_else(resource_changed::<Colormap>)
+                            .or_else(resource_changed::<HueOffset>),
+                    ),
+                // The Buddhabrot sampler is its own render mode: a random-orbit hit-count
+                // pass instead of per-pixel escape time.
+                render_buddhabrot
+                    .run_if(resource_equals(RenderMode::Buddhabrot))
+                    .run_if(
+                        resource_changed::<ComplexPlaneView>
+                            .or_else(on_event::<WindowResized>())
+                            .or_else(resource_changed::<RenderMode>)
+                            .or_else(resource_changed::<Colormap>)
+                            .or_else(resource_changed::<HueOffset>)
+                            .or_else(resource_changed::<BuddhabrotSamples>),
+                    ),
+                update_hud_text.run_if(
+                    resource_changed::<ComplexPlaneView>
+                        .or_else(resource_changed::<RenderMode>)
+                        .or_else(resource_changed::<Colormap>)
+                        .or_else(resource_changed::<HueOffset>)
+                        .or_else(resource_changed::<BuddhabrotSamples>)
+                        .or_else(resource_changed::<ExportResolution>),
                 ),
                 on_window_resized,
             ),
@@ -59,7 +226,49 @@ fn setup_camera(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
 }
 
-/// Creates the initial Image asset and spawns a sprite to display it.
+/// Spawns a screen-filling quad wired up to the `MandelbrotMaterial` shader.
+fn setup_mandelbrot_mesh(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<MandelbrotMaterial>>,
+    windows: Query<&Window>,
+) {
+    let window = windows.single();
+    let aspect_ratio = window.width() / window.height();
+
+    let mesh_handle = meshes.add(Mesh::from(shape::Quad::new(Vec2::new(
+        window.width(),
+        window.height(),
+    ))));
+
+    let material_handle = materials.add(MandelbrotMaterial {
+        params: MandelbrotParams {
+            // Matches `ComplexPlaneView::default`'s `center`/`seed`, so both deltas start at zero.
+            center_reference: Vec2::new(-0.75, 0.0),
+            center_delta: Vec2::ZERO,
+            scale: 3.5,
+            aspect: aspect_ratio,
+            iters: DEFAULT_ITERATIONS as i32,
+            seed_reference: Vec2::ZERO,
+            seed_delta: Vec2::ZERO,
+            mode: 0,
+            colormap: Colormap::default().shader_index(),
+            hue_offset: 0.0,
+        },
+    });
+    commands.insert_resource(MandelbrotMaterialHandle(material_handle.clone()));
+
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: mesh_handle.into(),
+            material: material_handle,
+            ..default()
+        },
+        MandelbrotMesh,
+    ));
+}
+
+/// Creates the CPU fallback's image asset and spawns a (initially hidden) sprite for it.
 fn setup_mandelbrot_image(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
@@ -88,12 +297,144 @@ fn setup_mandelbrot_image(
     commands.spawn((
         SpriteBundle {
             texture: image_handle,
+            visibility: Visibility::Hidden,
             ..default()
         },
         MandelbrotSprite,
     ));
 }
 
+/// Doubles (`T`) or halves (`G`) the iteration limit, trading detail for speed.
+fn adjust_iterations(keyboard: Res<ButtonInput<KeyCode>>, mut view: ResMut<ComplexPlaneView>) {
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        view.iterations = (view.iterations * 2).clamp(MIN_ITERATIONS, MAX_ITERATIONS);
+    } else if keyboard.just_pressed(KeyCode::KeyG) {
+        view.iterations = (view.iterations / 2).clamp(MIN_ITERATIONS, MAX_ITERATIONS);
+    }
+}
+
+/// Doubles (`Up`) or halves (`Down`) the Buddhabrot sample count.
+fn adjust_buddhabrot_samples(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut samples: ResMut<BuddhabrotSamples>,
+) {
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        samples.0 = (samples.0 * 2).clamp(MIN_BUDDHABROT_SAMPLES, MAX_BUDDHABROT_SAMPLES);
+    } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+        samples.0 = (samples.0 / 2).clamp(MIN_BUDDHABROT_SAMPLES, MAX_BUDDHABROT_SAMPLES);
+    }
+}
+
+/// Doubles (`=`) or halves (`-`) the PNG export resolution, independent of window size.
+fn adjust_export_resolution(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut resolution: ResMut<ExportResolution>,
+) {
+    if keyboard.just_pressed(KeyCode::Equal) {
+        resolution.width = (resolution.width * 2).clamp(MIN_EXPORT_DIMENSION, MAX_EXPORT_DIMENSION);
+        resolution.height =
+            (resolution.height * 2).clamp(MIN_EXPORT_DIMENSION, MAX_EXPORT_DIMENSION);
+    } else if keyboard.just_pressed(KeyCode::Minus) {
+        resolution.width = (resolution.width / 2).clamp(MIN_EXPORT_DIMENSION, MAX_EXPORT_DIMENSION);
+        resolution.height =
+            (resolution.height / 2).clamp(MIN_EXPORT_DIMENSION, MAX_EXPORT_DIMENSION);
+    }
+}
+
+/// Spawns the HUD text overlay showing the current center, scale, and iteration limit.
+fn setup_hud(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(8.0),
+            ..default()
+        }),
+        HudText,
+    ));
+}
+
+/// Refreshes the HUD text whenever the view or render mode changes.
+fn update_hud_text(
+    view: Res<ComplexPlaneView>,
+    mode: Res<RenderMode>,
+    colormap: Res<Colormap>,
+    hue_offset: Res<HueOffset>,
+    samples: Res<BuddhabrotSamples>,
+    export_resolution: Res<ExportResolution>,
+    mut hud_query: Query<&mut Text, With<HudText>>,
+) {
+    let mode_label = match *mode {
+        RenderMode::Gpu => "GPU".to_string(),
+        RenderMode::Cpu => "CPU".to_string(),
+        RenderMode::Buddhabrot => format!("Buddhabrot ({} samples)", samples.0),
+    };
+    let fractal_label = match view.mode {
+        FractalMode::Mandelbrot => "Mandelbrot".to_string(),
+        FractalMode::Julia => format!("Julia (seed {:.6}, {:.6})", view.seed.x, view.seed.y),
+    };
+    let colormap_label = if *colormap == Colormap::HsvCycle {
+        format!("{} (offset {:.0}°)", colormap.label(), hue_offset.0)
+    } else {
+        colormap.label().to_string()
+    };
+    if let Ok(mut text) = hud_query.get_single_mut() {
+        text.sections[0].value = format!(
+            "center: ({:.6}, {:.6})\nscale: {:.6e}\niterations: {}\nrenderer: {mode_label}\nfractal: {fractal_label}\ncolormap: {colormap_label}\nexport: {}x{} (+/-)",
+            view.center.x, view.center.y, view.scale, view.iterations,
+            export_resolution.width, export_resolution.height
+        );
+    }
+}
+
+/// Cycles to the next colormap palette with `C`.
+fn cycle_colormap(keyboard: Res<ButtonInput<KeyCode>>, mut colormap: ResMut<Colormap>) {
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        *colormap = colormap.next();
+    }
+}
+
+/// Adjusts the `HsvCycle` hue rotation with `[` / `]`.
+fn adjust_hue_offset(keyboard: Res<ButtonInput<KeyCode>>, mut hue_offset: ResMut<HueOffset>) {
+    const STEP_DEGREES: f32 = 15.0;
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        hue_offset.0 = (hue_offset.0 - STEP_DEGREES).rem_euclid(360.0);
+    } else if keyboard.just_pressed(KeyCode::BracketRight) {
+        hue_offset.0 = (hue_offset.0 + STEP_DEGREES).rem_euclid(360.0);
+    }
+}
+
+/// Cycles between the GPU shader, the CPU fallback rasterizer, and the Buddhabrot
+/// sampler with `Tab`.
+fn toggle_render_mode(keyboard: Res<ButtonInput<KeyCode>>, mut mode: ResMut<RenderMode>) {
+    if keyboard.just_pressed(KeyCode::Tab) {
+        *mode = match *mode {
+            RenderMode::Gpu => RenderMode::Cpu,
+            RenderMode::Cpu => RenderMode::Buddhabrot,
+            RenderMode::Buddhabrot => RenderMode::Gpu,
+        };
+    }
+}
+
+/// Shows whichever of the GPU mesh / CPU sprite matches the active `RenderMode`. The
+/// Buddhabrot sampler reuses the CPU fallback's sprite and image.
+fn update_render_mode_visibility(
+    mode: Res<RenderMode>,
+    mut mesh_query: Query<&mut Visibility, (With<MandelbrotMesh>, Without<MandelbrotSprite>)>,
+    mut sprite_query: Query<&mut Visibility, (With<MandelbrotSprite>, Without<MandelbrotMesh>)>,
+) {
+    let (mesh_visibility, sprite_visibility) = match *mode {
+        RenderMode::Gpu => (Visibility::Visible, Visibility::Hidden),
+        RenderMode::Cpu | RenderMode::Buddhabrot => (Visibility::Hidden, Visibility::Visible),
+    };
+    for mut visibility in &mut mesh_query {
+        *visibility = mesh_visibility;
+    }
+    for mut visibility in &mut sprite_query {
+        *visibility = sprite_visibility;
+    }
+}
+
 /// Handles panning the view by clicking and dragging the mouse.
 fn handle_panning(
     mut view: ResMut<ComplexPlaneView>,
@@ -149,29 +490,152 @@ fn handle_zoom(
     }
 }
 
-/// Resizes the underlying image asset when the window is resized.
+/// Handles right-click-drag to set the Julia seed live, and double-right-click to reset
+/// it to the origin (and fall back to Mandelbrot mode).
+fn handle_seed_drag(
+    mut view: ResMut<ComplexPlaneView>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    time: Res<Time>,
+    mut last_click_time: Local<Option<f32>>,
+    mut suppress_drag: Local<bool>,
+) {
+    if mouse_buttons.just_pressed(MouseButton::Right) {
+        let now = time.elapsed_seconds();
+        let is_double_click =
+            last_click_time.is_some_and(|last| now - last < DOUBLE_CLICK_WINDOW_SECS);
+        *last_click_time = Some(now);
+
+        if is_double_click {
+            view.seed = DVec2::ZERO;
+            view.mode = FractalMode::Mandelbrot;
+            // The button is still physically down from the second click; suppress the
+            // drag branch below until it's released, or this reset would be overwritten
+            // by the current cursor position on the very next frame.
+            *suppress_drag = true;
+            return;
+        }
+    }
+
+    if mouse_buttons.just_released(MouseButton::Right) {
+        *suppress_drag = false;
+    }
+
+    if *suppress_drag {
+        return;
+    }
+
+    if mouse_buttons.pressed(MouseButton::Right) {
+        let window = windows.single();
+        if let Some(cursor_pos) = window.cursor_position() {
+            // Convert cursor position to complex plane coordinates, exactly like `handle_zoom`.
+            let aspect_ratio = window.width() as f64 / window.height() as f64;
+            let complex_height = view.scale / aspect_ratio;
+
+            let complex_x = view.center.x - view.scale / 2.0
+                + (cursor_pos.x as f64 / window.width() as f64) * view.scale;
+            let complex_y = view.center.y + complex_height / 2.0
+                - (cursor_pos.y as f64 / window.height() as f64) * complex_height;
+
+            view.seed = DVec2::new(complex_x, complex_y);
+            view.mode = FractalMode::Julia;
+        }
+    }
+}
+
+/// Resizes the quad mesh and the CPU fallback's image when the window is resized,
+/// keeping both screen-filling.
 fn on_window_resized(
     mut events: EventReader<WindowResized>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mesh_query: Query<&Handle<Mesh>, With<MandelbrotMesh>>,
     mut images: ResMut<Assets<Image>>,
     mandelbrot_image: Res<MandelbrotImage>,
 ) {
     if let Some(event) = events.read().last() {
+        if let Ok(mesh_handle) = mesh_query.get_single() {
+            if let Some(mesh) = meshes.get_mut(mesh_handle) {
+                *mesh = Mesh::from(shape::Quad::new(Vec2::new(
+                    event.width as f32,
+                    event.height as f32,
+                )));
+            }
+        }
+
         if let Some(image) = images.get_mut(&mandelbrot_image.0) {
-            let new_size = Extent3d {
+            image.resize(Extent3d {
                 width: event.width as u32,
                 height: event.height as u32,
                 depth_or_array_layers: 1,
-            };
-            image.resize(new_size);
+            });
         }
     }
 }
 
-/// The core system that calculates and draws the Mandelbrot set onto the image.
+/// Pushes the current view (and window aspect ratio) into the shader's uniform buffer.
+///
+/// `center`/`seed` stay in `ComplexPlaneView` as f64 so CPU rendering never loses
+/// precision, but the GPU uniform is f32-only. Each tracks its own f64 reference point
+/// across frames and is uploaded as that reference plus the small delta from it, both
+/// narrowed to f32; the shader adds the delta back to the reference in f32, so the GPU
+/// image has f32 precision and loses detail at deep zoom. `center` and `seed` have
+/// separate reference points because in Julia mode the seed is fixed once via
+/// right-click-drag while `center` keeps moving as the user pans/zooms around it.
+fn update_mandelbrot_material(
+    view: Res<ComplexPlaneView>,
+    colormap: Res<Colormap>,
+    hue_offset: Res<HueOffset>,
+    windows: Query<&Window>,
+    mandelbrot_material: Res<MandelbrotMaterialHandle>,
+    mut materials: ResMut<Assets<MandelbrotMaterial>>,
+    mut center_reference: Local<Option<DVec2>>,
+    mut seed_reference: Local<Option<DVec2>>,
+) {
+    let window = windows.single();
+
+    // Re-center once the tracked point drifts more than `GPU_REFERENCE_RECENTER_FACTOR`
+    // view widths from its reference.
+    let center_drifted = center_reference.map_or(true, |r| {
+        (view.center - r).length() > view.scale * GPU_REFERENCE_RECENTER_FACTOR
+    });
+    if center_drifted {
+        *center_reference = Some(view.center);
+    }
+    let center_reference_point = center_reference.unwrap();
+
+    let seed_drifted = seed_reference.map_or(true, |r| {
+        (view.seed - r).length() > view.scale * GPU_REFERENCE_RECENTER_FACTOR
+    });
+    if seed_drifted {
+        *seed_reference = Some(view.seed);
+    }
+    let seed_reference_point = seed_reference.unwrap();
+
+    if let Some(material) = materials.get_mut(&mandelbrot_material.0) {
+        material.params.center_reference = center_reference_point.as_vec2();
+        material.params.center_delta = (view.center - center_reference_point).as_vec2();
+        material.params.scale = view.scale as f32;
+        material.params.aspect = window.width() / window.height();
+        material.params.iters = view.iterations as i32;
+        material.params.seed_reference = seed_reference_point.as_vec2();
+        material.params.seed_delta = (view.seed - seed_reference_point).as_vec2();
+        material.params.mode = match view.mode {
+            FractalMode::Mandelbrot => 0,
+            FractalMode::Julia => 1,
+        };
+        material.params.colormap = colormap.shader_index();
+        material.params.hue_offset = hue_offset.0;
+    }
+}
+
+/// The CPU fallback/offline rasterizer: computes and draws the Mandelbrot set onto
+/// the fallback image, distributing rows across a rayon thread pool.
 fn draw_mandelbrot_set(
     mut images: ResMut<Assets<Image>>,
     mandelbrot_image: Res<MandelbrotImage>,
     view: Res<ComplexPlaneView>,
+    colormap: Res<Colormap>,
+    hue_offset: Res<HueOffset>,
 ) {
     if let Some(image) = images.get_mut(&mandelbrot_image.0) {
         let width = image.texture_descriptor.size.width;
@@ -185,38 +649,124 @@ fn draw_mandelbrot_set(
         let x_max = view.center.x + view.scale / 2.0;
         let y_min = view.center.y - y_scale / 2.0;
         let y_max = view.center.y + y_scale / 2.0;
+        let max_iterations = view.iterations;
+        let mode = view.mode;
+        let seed = view.seed;
+        let colormap = *colormap;
+        let hue_offset = hue_offset.0;
 
-        // Iterate over every pixel in the image buffer.
-        for y in 0..height {
-            for x in 0..width {
-                let cx = map_range(x as f64, 0.0, (width - 1) as f64, x_min, x_max);
+        // Distribute the work across rows: each row is independent, so this scales
+        // cleanly with the number of available cores.
+        data.par_chunks_mut(width as usize * 4)
+            .enumerate()
+            .for_each(|(y, row)| {
                 let cy = map_range(y as f64, 0.0, (height - 1) as f64, y_max, y_min); // Y is inverted
 
-                let mut zx = 0.0;
-                let mut zy = 0.0;
-                let mut i = 0;
+                for x in 0..width as usize {
+                    let cx = map_range(x as f64, 0.0, (width - 1) as f64, x_min, x_max);
+                    let color =
+                        render_pixel(cx, cy, mode, seed, max_iterations, colormap, hue_offset);
 
-                while zx * zx + zy * zy < 4.0 && i < MAX_ITERATIONS {
-                    let temp_zx = zx * zx - zy * zy + cx;
-                    zy = 2.0 * zx * zy + cy;
-                    zx = temp_zx;
-                    i += 1;
+                    let pixel_index = x * 4;
+                    row[pixel_index..pixel_index + 4].copy_from_slice(&color);
                 }
+            });
+    }
+}
+
+/// Renders the Buddhabrot: samples random `c` points, keeps the full orbit of the ones
+/// that escape, and accumulates a hit count per pixel instead of an escape time.
+fn render_buddhabrot(
+    mut images: ResMut<Assets<Image>>,
+    mandelbrot_image: Res<MandelbrotImage>,
+    view: Res<ComplexPlaneView>,
+    colormap: Res<Colormap>,
+    hue_offset: Res<HueOffset>,
+    samples: Res<BuddhabrotSamples>,
+) {
+    if let Some(image) = images.get_mut(&mandelbrot_image.0) {
+        let width = image.texture_descriptor.size.width;
+        let height = image.texture_descriptor.size.height;
+
+        let aspect_ratio = width as f64 / height as f64;
+        let y_scale = view.scale / aspect_ratio;
+        let x_min = view.center.x - view.scale / 2.0;
+        let x_max = view.center.x + view.scale / 2.0;
+        let y_min = view.center.y - y_scale / 2.0;
+        let y_max = view.center.y + y_scale / 2.0;
+        let max_iterations = view.iterations;
+
+        let hit_counts: Vec<AtomicU32> = (0..(width as usize * height as usize))
+            .map(|_| AtomicU32::new(0))
+            .collect();
 
-                let color = if i == MAX_ITERATIONS {
-                    [0, 0, 0, 255]
-                } else {
-                    let n = i as f32 / MAX_ITERATIONS as f32;
-                    let r = (9.0 * (1.0 - n) * n * n * n * 255.0) as u8;
-                    let g = (15.0 * (1.0 - n) * (1.0 - n) * n * n * 255.0) as u8;
-                    let b = (8.5 * (1.0 - n) * (1.0 - n) * (1.0 - n) * n * 255.0) as u8;
-                    [r, g, b, 255]
-                };
-
-                let pixel_index = ((y * width) + x) as usize * 4;
-                data[pixel_index..pixel_index + 4].copy_from_slice(&color);
+        (0..samples.0).into_par_iter().for_each(|_| {
+            let mut rng = rand::thread_rng();
+            let cx = rng.gen_range(x_min..x_max);
+            let cy = rng.gen_range(y_min..y_max);
+
+            let mut zx = 0.0;
+            let mut zy = 0.0;
+            let mut orbit = Vec::with_capacity(64);
+            let mut escaped = false;
+
+            for _ in 0..max_iterations {
+                let temp_zx = zx * zx - zy * zy + cx;
+                zy = 2.0 * zx * zy + cy;
+                zx = temp_zx;
+                orbit.push((zx, zy));
+
+                if zx * zx + zy * zy > 4.0 {
+                    escaped = true;
+                    break;
+                }
             }
-        }
+
+            // Only escaped orbits are Buddhabrot material; bound (filled-in) points
+            // never get a trajectory to paint.
+            if !escaped {
+                return;
+            }
+
+            for (orbit_x, orbit_y) in orbit {
+                let px = map_range(orbit_x, x_min, x_max, 0.0, (width - 1) as f64).round();
+                let py = map_range(orbit_y, y_max, y_min, 0.0, (height - 1) as f64).round();
+                if px < 0.0 || py < 0.0 || px >= width as f64 || py >= height as f64 {
+                    continue;
+                }
+
+                let index = py as usize * width as usize + px as usize;
+                hit_counts[index].fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let max_hits = hit_counts
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let colormap = *colormap;
+        let hue_offset = hue_offset.0;
+        let data: &mut [u8] = image.data.as_mut();
+
+        // Normalize against the brightest pixel on a log curve, so faint, rarely-visited
+        // orbits stay visible next to the densest ones.
+        data.par_chunks_mut(width as usize * 4)
+            .enumerate()
+            .for_each(|(y, row)| {
+                for x in 0..width as usize {
+                    let index = y * width as usize + x;
+                    let hits = hit_counts[index].load(Ordering::Relaxed);
+                    let n =
+                        ((hits as f32 + 1.0).ln() / (max_hits as f32 + 1.0).ln()).clamp(0.0, 1.0);
+                    let color = colormap.color(n, hue_offset);
+
+                    let pixel_index = x * 4;
+                    row[pixel_index..pixel_index + 4].copy_from_slice(&color);
+                }
+            });
     }
 }
 
@@ -224,3 +774,120 @@ fn draw_mandelbrot_set(
 fn map_range(val: f64, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> f64 {
     (val - in_min) * (out_max - out_min) / (in_max - in_min) + out_min
 }
+
+/// Escape-time-iterates a single pixel and maps it through the active colormap. Shared by
+/// the CPU fallback rasterizer and the PNG exporter so the two stay pixel-for-pixel identical.
+fn render_pixel(
+    cx: f64,
+    cy: f64,
+    mode: FractalMode,
+    seed: DVec2,
+    max_iterations: u32,
+    colormap: Colormap,
+    hue_offset: f32,
+) -> [u8; 4] {
+    // Mandelbrot holds z0 at the origin and varies c per-pixel; Julia holds c fixed at
+    // the seed and varies z0 per-pixel instead.
+    let (mut zx, mut zy, cx, cy) = match mode {
+        FractalMode::Mandelbrot => (0.0, 0.0, cx, cy),
+        FractalMode::Julia => (cx, cy, seed.x, seed.y),
+    };
+    let mut i = 0;
+
+    // A larger bailout radius (vs. the textbook 4.0) keeps the smooth coloring below
+    // accurate near the escape boundary.
+    while zx * zx + zy * zy < 256.0 && i < max_iterations {
+        let temp_zx = zx * zx - zy * zy + cx;
+        zy = 2.0 * zx * zy + cy;
+        zx = temp_zx;
+        i += 1;
+    }
+
+    if i == max_iterations {
+        [0, 0, 0, 255]
+    } else {
+        // Smooth (continuous) coloring: turn the integer escape count into a fractional
+        // iteration count to remove banding.
+        let mu = i as f64 + 1.0 - ((zx * zx + zy * zy).sqrt().ln()).ln() / std::f64::consts::LN_2;
+        let n = ((mu / max_iterations as f64) as f32).clamp(0.0, 1.0);
+        colormap.color(n, hue_offset)
+    }
+}
+
+// The resolution used by `export_view_to_png`, independent of the window's own size.
+// Adjusted at runtime with `=`/`-` via `adjust_export_resolution`.
+#[derive(Resource, Clone, Copy)]
+struct ExportResolution {
+    width: u32,
+    height: u32,
+}
+
+impl Default for ExportResolution {
+    fn default() -> Self {
+        Self {
+            width: 3840,
+            height: 2160,
+        }
+    }
+}
+
+/// Renders the current view into an offscreen buffer at `ExportResolution` and writes it
+/// to disk as a PNG, bound to `P`. The filename encodes the center/scale/iteration count
+/// so a render can be reproduced later.
+fn export_view_to_png(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    view: Res<ComplexPlaneView>,
+    colormap: Res<Colormap>,
+    hue_offset: Res<HueOffset>,
+    resolution: Res<ExportResolution>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+
+    let width = resolution.width;
+    let height = resolution.height;
+    let aspect_ratio = width as f64 / height as f64;
+    let y_scale = view.scale / aspect_ratio;
+    let x_min = view.center.x - view.scale / 2.0;
+    let x_max = view.center.x + view.scale / 2.0;
+    let y_min = view.center.y - y_scale / 2.0;
+    let y_max = view.center.y + y_scale / 2.0;
+    let max_iterations = view.iterations;
+    let mode = view.mode;
+    let seed = view.seed;
+    let colormap = *colormap;
+    let hue_offset = hue_offset.0;
+
+    let mut data = vec![0u8; (width * height * 4) as usize];
+    data.par_chunks_mut(width as usize * 4)
+        .enumerate()
+        .for_each(|(y, row)| {
+            let cy = map_range(y as f64, 0.0, (height - 1) as f64, y_max, y_min);
+            for x in 0..width as usize {
+                let cx = map_range(x as f64, 0.0, (width - 1) as f64, x_min, x_max);
+                let color = render_pixel(cx, cy, mode, seed, max_iterations, colormap, hue_offset);
+                let pixel_index = x * 4;
+                row[pixel_index..pixel_index + 4].copy_from_slice(&color);
+            }
+        });
+
+    let Some(image_buffer) = RgbaImage::from_raw(width, height, data) else {
+        error!("export image dimensions {width}x{height} do not match the pixel buffer size");
+        return;
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let filename = format!(
+        "mandelbrot_c{:.6}_{:.6}_s{:.6e}_i{}_{}.png",
+        view.center.x, view.center.y, view.scale, max_iterations, timestamp
+    );
+
+    match image_buffer.save(&filename) {
+        Ok(()) => info!("exported {width}x{height} PNG to {filename}"),
+        Err(err) => error!("failed to save exported PNG to {filename}: {err}"),
+    }
+}